@@ -0,0 +1,116 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::api::base::Result;
+use crate::api::order::{AsSymbol, Symbol};
+use crate::client::TastyTrade;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct Instrument {
+    pub symbol: Symbol,
+    pub active: bool,
+    pub is_closing_only: bool,
+    #[serde(with = "rust_decimal::serde::arbitrary_precision")]
+    pub tick_size: Decimal,
+    #[serde(with = "rust_decimal::serde::arbitrary_precision")]
+    pub lot_size: Decimal,
+}
+
+/// A price or quantity in an [`crate::api::order::Order`] that does not
+/// line up with an [`Instrument`]'s tick size, lot size, or trading status.
+#[derive(Debug)]
+pub enum InstrumentValidationError {
+    PriceNotAlignedToTick {
+        price: Decimal,
+        tick_size: Decimal,
+    },
+    QuantityNotAlignedToLotSize {
+        symbol: Symbol,
+        quantity: Decimal,
+        lot_size: Decimal,
+    },
+    ClosingOnly {
+        symbol: Symbol,
+    },
+    SymbolMismatch {
+        leg_symbol: Symbol,
+        instrument_symbol: Symbol,
+    },
+    MissingInstrument {
+        symbol: Symbol,
+    },
+}
+
+impl std::fmt::Display for InstrumentValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InstrumentValidationError::PriceNotAlignedToTick { price, tick_size } => write!(
+                f,
+                "price {price} is not aligned to the instrument's tick size of {tick_size}"
+            ),
+            InstrumentValidationError::QuantityNotAlignedToLotSize {
+                symbol,
+                quantity,
+                lot_size,
+            } => write!(
+                f,
+                "quantity {quantity} for {} is not aligned to the instrument's lot size of {lot_size}",
+                symbol.0
+            ),
+            InstrumentValidationError::ClosingOnly { symbol } => {
+                write!(f, "{} is closing-only", symbol.0)
+            }
+            InstrumentValidationError::SymbolMismatch {
+                leg_symbol,
+                instrument_symbol,
+            } => write!(
+                f,
+                "leg symbol {} does not match instrument {}",
+                leg_symbol.0, instrument_symbol.0
+            ),
+            InstrumentValidationError::MissingInstrument { symbol } => {
+                write!(f, "no instrument metadata was provided for {}", symbol.0)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InstrumentValidationError {}
+
+pub(crate) fn is_aligned(value: Decimal, increment: Decimal) -> bool {
+    increment.is_zero() || (value % increment).is_zero()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_increment_is_always_aligned() {
+        assert!(is_aligned(Decimal::new(12345, 2), Decimal::ZERO));
+    }
+
+    #[test]
+    fn value_on_increment_boundary_is_aligned() {
+        assert!(is_aligned(Decimal::new(100, 2), Decimal::new(5, 2)));
+    }
+
+    #[test]
+    fn value_off_increment_boundary_is_not_aligned() {
+        assert!(!is_aligned(Decimal::new(103, 2), Decimal::new(5, 2)));
+    }
+}
+
+impl TastyTrade {
+    pub async fn get_equity(&self, symbol: impl AsSymbol) -> Result<Instrument> {
+        let symbol = symbol.as_symbol();
+        self.get(format!("/instruments/equities/{}", symbol.0)).await
+    }
+
+    pub async fn get_option(&self, symbol: impl AsSymbol) -> Result<Instrument> {
+        let symbol = symbol.as_symbol();
+        self.get(format!("/instruments/equity-options/{}", symbol.0))
+            .await
+    }
+}
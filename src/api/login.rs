@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize)]
+pub(crate) struct LoginCredentials<'a> {
+    pub login: &'a str,
+    pub password: &'a str,
+    #[serde(rename = "remember-me")]
+    pub remember_me: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct RememberMeCredentials<'a> {
+    pub login: &'a str,
+    #[serde(rename = "remember-token")]
+    pub remember_token: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct LoginResponse {
+    pub session_token: String,
+    pub remember_token: Option<String>,
+}
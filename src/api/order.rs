@@ -1,8 +1,13 @@
+use std::collections::HashMap;
+
 use derive_builder::Builder;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use crate::accounts::AccountNumber;
+use crate::api::base::Result;
+use crate::api::instrument::{is_aligned, Instrument, InstrumentValidationError};
+use crate::client::TastyTrade;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum PriceEffect {
@@ -153,9 +158,17 @@ pub struct LiveOrderLeg {
     pub fills: Vec<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum TrailingStop {
+    #[serde(rename = "trailing-stop-amount")]
+    Amount(#[serde(with = "rust_decimal::serde::arbitrary_precision")] Decimal),
+    #[serde(rename = "trailing-stop-percent")]
+    Percent(#[serde(with = "rust_decimal::serde::arbitrary_precision")] Decimal),
+}
+
 #[derive(Builder, Serialize)]
 #[serde(rename_all = "kebab-case")]
-#[builder(setter(into))]
+#[builder(setter(into), build_fn(validate = "Self::validate"))]
 pub struct Order {
     time_in_force: TimeInForce,
     order_type: OrderType,
@@ -164,6 +177,43 @@ pub struct Order {
     price: Decimal,
     price_effect: PriceEffect,
     legs: Vec<OrderLeg>,
+
+    #[serde(
+        with = "rust_decimal::serde::arbitrary_precision_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[builder(default, setter(into, strip_option))]
+    stop_trigger: Option<Decimal>,
+
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    trailing_stop: Option<TrailingStop>,
+}
+
+impl OrderBuilder {
+    fn validate(&self) -> std::result::Result<(), String> {
+        let order_type = self.order_type.as_ref();
+
+        if let Some(Some(_)) = self.trailing_stop.as_ref() {
+            match order_type {
+                Some(OrderType::Stop) | Some(OrderType::StopLimit) => {}
+                _ => {
+                    return Err(
+                        "trailing-stop can only be set on a Stop or Stop Limit order".into(),
+                    )
+                }
+            }
+        }
+
+        if matches!(order_type, Some(OrderType::Stop) | Some(OrderType::StopLimit)) {
+            let has_stop_trigger = matches!(self.stop_trigger, Some(Some(_)));
+            if !has_stop_trigger {
+                return Err("stop-trigger is required for a Stop or Stop Limit order".into());
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Builder, Serialize, Deserialize, Clone, Debug)]
@@ -280,12 +330,289 @@ pub struct Warning {
     pub message: String,
 }
 
+impl OrderLeg {
+    /// Checks this leg's quantity against `instrument`'s lot size, and that
+    /// the instrument can still accept the leg's action (e.g. closing-only
+    /// instruments reject anything but a close).
+    ///
+    /// `instrument` must describe this leg's own symbol — a multi-leg order
+    /// (vertical, iron condor, ...) can have a different underlying per leg,
+    /// so passing the wrong instrument is rejected rather than silently
+    /// validating against unrelated metadata.
+    pub fn validate_against(
+        &self,
+        instrument: &Instrument,
+    ) -> std::result::Result<(), InstrumentValidationError> {
+        if self.symbol != instrument.symbol {
+            return Err(InstrumentValidationError::SymbolMismatch {
+                leg_symbol: self.symbol.clone(),
+                instrument_symbol: instrument.symbol.clone(),
+            });
+        }
+
+        if instrument.is_closing_only
+            && !matches!(self.action, Action::SellToClose | Action::BuyToClose)
+        {
+            return Err(InstrumentValidationError::ClosingOnly {
+                symbol: self.symbol.clone(),
+            });
+        }
+
+        if !is_aligned(self.quantity, instrument.lot_size) {
+            return Err(InstrumentValidationError::QuantityNotAlignedToLotSize {
+                symbol: self.symbol.clone(),
+                quantity: self.quantity,
+                lot_size: instrument.lot_size,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Order {
+    /// Checks each leg's quantity against the matching instrument's lot
+    /// size, and this order's net price against the widest (most
+    /// restrictive) tick size across every leg's instrument, before the
+    /// order is sent.
+    ///
+    /// `instruments` must contain an entry for every leg's symbol, keyed by
+    /// that symbol — a multi-leg order can span several underlyings, each
+    /// with its own tick and lot size, so a single `&Instrument` isn't
+    /// enough to validate the whole order. Checking against only one leg's
+    /// tick size would let a net price through that's actually misaligned
+    /// for a coarser-ticked leg, so the largest tick size wins.
+    pub fn validate_against(
+        &self,
+        instruments: &HashMap<Symbol, Instrument>,
+    ) -> std::result::Result<(), InstrumentValidationError> {
+        let mut widest_tick_size: Option<Decimal> = None;
+
+        for leg in &self.legs {
+            let instrument = instruments.get(&leg.symbol).ok_or_else(|| {
+                InstrumentValidationError::MissingInstrument {
+                    symbol: leg.symbol.clone(),
+                }
+            })?;
+            leg.validate_against(instrument)?;
+
+            widest_tick_size = Some(match widest_tick_size {
+                Some(widest) if widest >= instrument.tick_size => widest,
+                _ => instrument.tick_size,
+            });
+        }
+
+        if let Some(tick_size) = widest_tick_size {
+            if !is_aligned(self.price, tick_size) {
+                return Err(InstrumentValidationError::PriceNotAlignedToTick {
+                    price: self.price,
+                    tick_size,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl TastyTrade {
+    pub async fn cancel_order(
+        &self,
+        account: &AccountNumber,
+        order_id: &OrderId,
+    ) -> Result<LiveOrderRecord> {
+        self.delete(format!("/accounts/{}/orders/{}", account.0, order_id.0))
+            .await
+    }
+
+    pub async fn replace_order(
+        &self,
+        account: &AccountNumber,
+        order_id: &OrderId,
+        order: &Order,
+    ) -> Result<LiveOrderRecord> {
+        self.put(
+            format!("/accounts/{}/orders/{}", account.0, order_id.0),
+            order,
+        )
+        .await
+    }
+
+    pub async fn dry_run_order(&self, account: &AccountNumber, order: &Order) -> Result<DryRunResult> {
+        self.post(format!("/accounts/{}/orders/dry-run", account.0), order)
+            .await
+    }
+}
+
 // Tests
 #[cfg(test)]
 mod tests {
     use super::*;
     use serde_json::json;
 
+    fn leg(symbol: &str, quantity: Decimal, action: Action) -> OrderLeg {
+        OrderLegBuilder::default()
+            .instrument_type(InstrumentType::Equity)
+            .symbol(symbol)
+            .quantity(quantity)
+            .action(action)
+            .build()
+            .unwrap()
+    }
+
+    fn order_builder() -> OrderBuilder {
+        let mut builder = OrderBuilder::default();
+        builder
+            .time_in_force(TimeInForce::Day)
+            .order_type(OrderType::Limit)
+            .price(Decimal::new(100, 2))
+            .price_effect(PriceEffect::Debit)
+            .legs(vec![leg("AAPL", Decimal::ONE, Action::BuyToOpen)]);
+        builder
+    }
+
+    #[test]
+    fn limit_order_without_stop_trigger_or_trailing_stop_builds() {
+        assert!(order_builder().build().is_ok());
+    }
+
+    #[test]
+    fn stop_order_without_stop_trigger_is_rejected() {
+        let mut builder = order_builder();
+        builder.order_type(OrderType::Stop);
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn stop_order_with_stop_trigger_builds() {
+        let mut builder = order_builder();
+        builder
+            .order_type(OrderType::Stop)
+            .stop_trigger(Decimal::new(95, 2));
+        assert!(builder.build().is_ok());
+    }
+
+    #[test]
+    fn trailing_stop_on_limit_order_is_rejected() {
+        let mut builder = order_builder();
+        builder.trailing_stop(TrailingStop::Amount(Decimal::ONE));
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn trailing_stop_on_stop_order_builds() {
+        let mut builder = order_builder();
+        builder
+            .order_type(OrderType::Stop)
+            .stop_trigger(Decimal::new(95, 2))
+            .trailing_stop(TrailingStop::Percent(Decimal::ONE));
+        assert!(builder.build().is_ok());
+    }
+
+    fn instrument(symbol: &str, tick_size: Decimal, lot_size: Decimal) -> Instrument {
+        Instrument {
+            symbol: symbol.as_symbol(),
+            active: true,
+            is_closing_only: false,
+            tick_size,
+            lot_size,
+        }
+    }
+
+    #[test]
+    fn validate_against_rejects_mismatched_leg_symbol() {
+        let order_leg = leg("AAPL", Decimal::ONE, Action::BuyToOpen);
+        let msft = instrument("MSFT", Decimal::new(1, 2), Decimal::ONE);
+
+        assert!(matches!(
+            order_leg.validate_against(&msft),
+            Err(InstrumentValidationError::SymbolMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn validate_against_rejects_closing_only_on_open() {
+        let order_leg = leg("AAPL", Decimal::ONE, Action::BuyToOpen);
+        let mut aapl = instrument("AAPL", Decimal::new(1, 2), Decimal::ONE);
+        aapl.is_closing_only = true;
+
+        assert!(matches!(
+            order_leg.validate_against(&aapl),
+            Err(InstrumentValidationError::ClosingOnly { .. })
+        ));
+    }
+
+    #[test]
+    fn validate_against_rejects_quantity_off_lot_size() {
+        let order_leg = leg("AAPL", Decimal::new(15, 1), Action::BuyToOpen);
+        let aapl = instrument("AAPL", Decimal::new(1, 2), Decimal::ONE);
+
+        assert!(matches!(
+            order_leg.validate_against(&aapl),
+            Err(InstrumentValidationError::QuantityNotAlignedToLotSize { .. })
+        ));
+    }
+
+    #[test]
+    fn order_validate_against_requires_an_instrument_per_leg() {
+        let order = order_builder().build().unwrap();
+        let instruments = HashMap::new();
+
+        assert!(matches!(
+            order.validate_against(&instruments),
+            Err(InstrumentValidationError::MissingInstrument { .. })
+        ));
+    }
+
+    #[test]
+    fn order_validate_against_checks_price_against_each_legs_tick_size() {
+        let order = order_builder().build().unwrap();
+        let mut instruments = HashMap::new();
+        instruments.insert("AAPL".as_symbol(), instrument("AAPL", Decimal::new(5, 2), Decimal::ONE));
+
+        // order_builder() sets a price of 1.00, which is aligned to a 0.05 tick.
+        assert!(order.validate_against(&instruments).is_ok());
+    }
+
+    #[test]
+    fn order_validate_against_rejects_price_off_tick_size() {
+        let mut builder = order_builder();
+        builder.price(Decimal::new(103, 2));
+        let order = builder.build().unwrap();
+
+        let mut instruments = HashMap::new();
+        instruments.insert("AAPL".as_symbol(), instrument("AAPL", Decimal::new(5, 2), Decimal::ONE));
+
+        assert!(matches!(
+            order.validate_against(&instruments),
+            Err(InstrumentValidationError::PriceNotAlignedToTick { .. })
+        ));
+    }
+
+    #[test]
+    fn order_validate_against_uses_the_widest_tick_size_across_legs() {
+        // The first leg's tick size (0.01) alone would accept 1.03, but the
+        // second leg's coarser 0.05 tick should still reject it.
+        let mut builder = order_builder();
+        builder
+            .price(Decimal::new(103, 2))
+            .legs(vec![
+                leg("AAPL", Decimal::ONE, Action::BuyToOpen),
+                leg("MSFT", Decimal::ONE, Action::SellToOpen),
+            ]);
+        let order = builder.build().unwrap();
+
+        let mut instruments = HashMap::new();
+        instruments.insert("AAPL".as_symbol(), instrument("AAPL", Decimal::new(1, 2), Decimal::ONE));
+        instruments.insert("MSFT".as_symbol(), instrument("MSFT", Decimal::new(5, 2), Decimal::ONE));
+
+        assert!(matches!(
+            order.validate_against(&instruments),
+            Err(InstrumentValidationError::PriceNotAlignedToTick { tick_size, .. })
+                if tick_size == Decimal::new(5, 2)
+        ));
+    }
+
     #[test]
     fn test_derp() {
         let json = json!({
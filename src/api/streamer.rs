@@ -0,0 +1,229 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_util::stream::Stream;
+use futures_util::{SinkExt, StreamExt};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::accounts::AccountNumber;
+use crate::api::order::{LiveOrderRecord, Symbol};
+use crate::client::TastyTrade;
+
+const STREAMER_URL: &str = "wss://streamer.cert.tastyworks.com";
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
+const MAX_RECONNECT_BACKOFF: Duration = Duration::from_secs(30);
+/// A connection has to stay open at least this long before a reconnect is
+/// treated as a fresh start (`attempt` reset to 0) rather than another hop
+/// in the same backoff sequence. Without this, a server that cleanly closes
+/// the connection immediately after accepting it would reconnect in a tight,
+/// unthrottled loop instead of backing off.
+const MIN_STABLE_CONNECTION: Duration = Duration::from_secs(5);
+
+/// Exponential backoff before the `attempt`-th reconnect, mirroring the
+/// retry delay `client.rs`'s `request()` uses for rate-limited/5xx
+/// responses, capped at `MAX_RECONNECT_BACKOFF` so an extended outage
+/// doesn't blow up into an hours-long wait.
+fn reconnect_backoff(attempt: u32) -> Duration {
+    Duration::from_millis(200 * 2u64.pow(attempt.min(10))).min(MAX_RECONNECT_BACKOFF)
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct ConnectFrame<'a> {
+    action: &'static str,
+    value: &'a [AccountNumber],
+    #[serde(rename = "auth-token")]
+    auth_token: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct HeartbeatFrame<'a> {
+    action: &'static str,
+    #[serde(rename = "auth-token")]
+    auth_token: &'a str,
+}
+
+/// A single frame pushed over the account streamer websocket, routed by its
+/// `type`/`data` discriminator into a typed variant.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "kebab-case")]
+pub enum AccountStreamerEvent {
+    OrderUpdate(LiveOrderRecord),
+    AccountBalance(AccountBalanceUpdate),
+    Position(PositionUpdate),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct AccountBalanceUpdate {
+    pub account_number: AccountNumber,
+    #[serde(with = "rust_decimal::serde::arbitrary_precision")]
+    pub cash_balance: Decimal,
+    #[serde(with = "rust_decimal::serde::arbitrary_precision")]
+    pub net_liquidating_value: Decimal,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PositionUpdate {
+    pub account_number: AccountNumber,
+    pub symbol: Symbol,
+    pub quantity: Decimal,
+}
+
+/// A failure reading the account streamer websocket. Unlike a normal
+/// [`crate::api::base::TastyError`], this surfaces parse failures instead of
+/// silently dropping the offending frame.
+#[derive(Debug)]
+pub enum StreamerError {
+    Connect(tokio_tungstenite::tungstenite::Error),
+    Decode(serde_json::Error),
+}
+
+impl std::fmt::Display for StreamerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamerError::Connect(e) => write!(f, "account streamer connection error: {e}"),
+            StreamerError::Decode(e) => write!(f, "account streamer frame decode error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for StreamerError {}
+
+/// A typed stream of account events (order, balance and position updates)
+/// from the tastytrade account streamer.
+pub struct AccountStreamer {
+    events: mpsc::Receiver<Result<AccountStreamerEvent, StreamerError>>,
+}
+
+impl Stream for AccountStreamer {
+    type Item = Result<AccountStreamerEvent, StreamerError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.events.poll_recv(cx)
+    }
+}
+
+impl TastyTrade {
+    /// Opens a websocket connection to the tastytrade account streamer,
+    /// subscribes to `accounts`, and returns a [`Stream`] of account events.
+    ///
+    /// The connection reconnects and resubscribes automatically if it drops;
+    /// a heartbeat frame is sent roughly every 30 seconds to keep it alive.
+    pub async fn stream_accounts(&self, accounts: &[AccountNumber]) -> AccountStreamer {
+        let (tx, rx) = mpsc::channel(128);
+        let accounts = accounts.to_vec();
+        let tasty = self.clone();
+
+        tokio::spawn(async move {
+            let mut attempt = 0u32;
+            loop {
+                let connected_at = tokio::time::Instant::now();
+                let result = run_streamer(&tasty, &accounts, &tx).await;
+
+                if let Err(err) = result {
+                    if tx.send(Err(err)).await.is_err() {
+                        return;
+                    }
+                }
+
+                if tx.is_closed() {
+                    return;
+                }
+
+                // A connection that stayed up for a while (even one that
+                // ended in a clean close) is treated as a fresh start; one
+                // that dropped almost immediately — whether from an error or
+                // a clean close — keeps backing off so a server that rejects
+                // or closes the connection right away can't drive a tight
+                // reconnect loop.
+                if connected_at.elapsed() >= MIN_STABLE_CONNECTION {
+                    attempt = 0;
+                } else {
+                    tokio::time::sleep(reconnect_backoff(attempt)).await;
+                    attempt = attempt.saturating_add(1);
+                }
+            }
+        });
+
+        AccountStreamer { events: rx }
+    }
+}
+
+async fn run_streamer(
+    tasty: &TastyTrade,
+    accounts: &[AccountNumber],
+    tx: &mpsc::Sender<Result<AccountStreamerEvent, StreamerError>>,
+) -> Result<(), StreamerError> {
+    let (ws, _) = tokio_tungstenite::connect_async(STREAMER_URL)
+        .await
+        .map_err(StreamerError::Connect)?;
+    let (mut write, mut read) = ws.split();
+
+    let auth_token = tasty.session_token().await;
+    let connect_frame = ConnectFrame {
+        action: "connect",
+        value: accounts,
+        auth_token: &auth_token,
+    };
+    write
+        .send(Message::Text(serde_json::to_string(&connect_frame).unwrap()))
+        .await
+        .map_err(StreamerError::Connect)?;
+
+    let mut heartbeat = tokio::time::interval(HEARTBEAT_INTERVAL);
+    heartbeat.tick().await;
+
+    loop {
+        tokio::select! {
+            _ = heartbeat.tick() => {
+                let frame = HeartbeatFrame {
+                    action: "heartbeat",
+                    auth_token: &auth_token,
+                };
+                write
+                    .send(Message::Text(serde_json::to_string(&frame).unwrap()))
+                    .await
+                    .map_err(StreamerError::Connect)?;
+            }
+            message = read.next() => {
+                let Some(message) = message else {
+                    return Ok(());
+                };
+                let message = message.map_err(StreamerError::Connect)?;
+                let Message::Text(text) = message else {
+                    continue;
+                };
+
+                let event = serde_json::from_str::<AccountStreamerEvent>(&text)
+                    .map_err(StreamerError::Decode);
+                if tx.send(event).await.is_err() {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reconnect_backoff_doubles_each_attempt() {
+        assert_eq!(reconnect_backoff(0), Duration::from_millis(200));
+        assert_eq!(reconnect_backoff(1), Duration::from_millis(400));
+        assert_eq!(reconnect_backoff(2), Duration::from_millis(800));
+    }
+
+    #[test]
+    fn reconnect_backoff_caps_at_the_maximum() {
+        assert_eq!(reconnect_backoff(10), MAX_RECONNECT_BACKOFF);
+        assert_eq!(reconnect_backoff(100), MAX_RECONNECT_BACKOFF);
+    }
+}
@@ -0,0 +1,187 @@
+use derive_builder::Builder;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::accounts::AccountNumber;
+use crate::api::base::Result;
+use crate::api::order::{InstrumentType, PriceEffect, Symbol};
+use crate::client::TastyTrade;
+
+const TRANSACTIONS_PER_PAGE: u32 = 250;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum TransactionType {
+    Trade,
+    #[serde(rename = "Money Movement")]
+    MoneyMovement,
+    #[serde(rename = "Receive Deliver")]
+    ReceiveDeliver,
+}
+
+/// A date-range query against the account transaction history, built up the
+/// same way an [`crate::api::order::Order`] is: set only the filters you
+/// need, the rest are left off the request.
+#[derive(Builder, Default)]
+#[builder(setter(into, strip_option), default)]
+pub struct TransactionQuery {
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub instrument_type: Option<InstrumentType>,
+    pub symbol: Option<Symbol>,
+    pub transaction_type: Option<TransactionType>,
+}
+
+impl TransactionQuery {
+    fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = Vec::new();
+
+        if let Some(start_date) = &self.start_date {
+            pairs.push(("start-date", start_date.clone()));
+        }
+        if let Some(end_date) = &self.end_date {
+            pairs.push(("end-date", end_date.clone()));
+        }
+        if let Some(instrument_type) = &self.instrument_type {
+            pairs.push(("instrument-type", unquoted_json(instrument_type)));
+        }
+        if let Some(symbol) = &self.symbol {
+            pairs.push(("symbol", symbol.0.clone()));
+        }
+        if let Some(transaction_type) = &self.transaction_type {
+            pairs.push(("type", unquoted_json(transaction_type)));
+        }
+
+        pairs
+    }
+}
+
+fn unquoted_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value)
+        .unwrap()
+        .trim_matches('"')
+        .to_owned()
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct Transaction {
+    pub id: Decimal,
+    pub account_number: AccountNumber,
+    pub transaction_type: TransactionType,
+    pub symbol: Option<Symbol>,
+    pub instrument_type: Option<InstrumentType>,
+    pub description: String,
+    #[serde(with = "rust_decimal::serde::arbitrary_precision")]
+    pub value: Decimal,
+    pub value_effect: PriceEffect,
+    pub executed_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct TransactionsPage {
+    items: Vec<Transaction>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unquoted_json_strips_the_surrounding_quotes() {
+        assert_eq!(unquoted_json(&TransactionType::MoneyMovement), "Money Movement");
+    }
+
+    #[test]
+    fn empty_query_has_no_pairs() {
+        assert!(TransactionQueryBuilder::default()
+            .build()
+            .unwrap()
+            .query_pairs()
+            .is_empty());
+    }
+
+    #[test]
+    fn query_pairs_renders_every_filter() {
+        let query = TransactionQueryBuilder::default()
+            .start_date("2024-01-01")
+            .end_date("2024-02-01")
+            .instrument_type(InstrumentType::Equity)
+            .symbol("AAPL")
+            .transaction_type(TransactionType::Trade)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            query.query_pairs(),
+            vec![
+                ("start-date", "2024-01-01".to_owned()),
+                ("end-date", "2024-02-01".to_owned()),
+                ("instrument-type", "Equity".to_owned()),
+                ("symbol", "AAPL".to_owned()),
+                ("type", "Trade".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn encode_query_pairs_percent_encodes_reserved_characters() {
+        let query_string = encode_query_pairs(&[
+            ("start-date", "2024-02-11T21:59:57.143+00:00".to_owned()),
+            ("symbol", "A&B".to_owned()),
+        ]);
+
+        assert_eq!(
+            query_string,
+            "start-date=2024-02-11T21%3A59%3A57.143%2B00%3A00&symbol=A%26B"
+        );
+    }
+}
+
+/// Form/percent-encodes `pairs` into a query string, so values containing
+/// reserved characters (e.g. the `+` in an ISO-8601 timestamp's UTC offset)
+/// survive a round trip instead of being decoded as a space by the server.
+fn encode_query_pairs(pairs: &[(&'static str, String)]) -> String {
+    url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(pairs.iter().map(|(key, value)| (*key, value.as_str())))
+        .finish()
+}
+
+impl TastyTrade {
+    /// Fetches the account's transaction history matching `query`,
+    /// transparently following pagination until a page with fewer than
+    /// `TRANSACTIONS_PER_PAGE` items is returned.
+    pub async fn get_transactions(
+        &self,
+        account: &AccountNumber,
+        query: &TransactionQuery,
+    ) -> Result<Vec<Transaction>> {
+        let mut transactions = Vec::new();
+        let mut page_offset = 0u32;
+
+        loop {
+            let mut pairs = query.query_pairs();
+            pairs.push(("per-page", TRANSACTIONS_PER_PAGE.to_string()));
+            pairs.push(("page-offset", page_offset.to_string()));
+
+            let query_string = encode_query_pairs(&pairs);
+
+            let page: TransactionsPage = self
+                .get(format!(
+                    "/accounts/{}/transactions?{query_string}",
+                    account.0
+                ))
+                .await?;
+
+            let page_len = page.items.len();
+            transactions.extend(page.items);
+
+            if page_len < TRANSACTIONS_PER_PAGE as usize {
+                break;
+            }
+            page_offset += 1;
+        }
+
+        Ok(transactions)
+    }
+}
@@ -1,24 +1,94 @@
+use std::sync::Arc;
+use std::time::Duration;
+
 use reqwest::header;
 use reqwest::header::HeaderMap;
 use reqwest::header::HeaderValue;
 use reqwest::ClientBuilder;
+use reqwest::Method;
+use reqwest::StatusCode;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
+use tokio::sync::RwLock;
+use tokio::sync::Semaphore;
 
 use crate::api::base::Result;
 use crate::api::base::TastyApiResponse;
 use crate::api::base::TastyError;
 use crate::api::login::LoginCredentials;
 use crate::api::login::LoginResponse;
+use crate::api::login::RememberMeCredentials;
 
 use reqwest_inspect_json::InspectJson;
 
 pub const BASE_URL: &str = "https://api.cert.tastyworks.com";
 
+/// Client-side request throttling and retry budget.
+///
+/// The default is conservative enough to stay under tastytrade's published
+/// rate limits; override it with [`TastyTrade::with_rate_limit`] to tune it
+/// per environment (e.g. a looser budget against the sandbox).
+#[derive(Debug, Clone)]
+pub struct RateLimitConfig {
+    /// Maximum number of requests allowed per `interval`.
+    pub max_requests: u32,
+    /// The window over which `max_requests` is replenished.
+    pub interval: Duration,
+    /// How many times a rate-limited (429) or transient (5xx) response is
+    /// retried, with exponential backoff, before giving up.
+    pub max_retries: u32,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            max_requests: 10,
+            interval: Duration::from_secs(1),
+            max_retries: 5,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TastyTrade {
+    login: String,
+    remember_token: Arc<RwLock<Option<String>>>,
+    session: Arc<RwLock<Session>>,
+    rate_limiter: Arc<Semaphore>,
+    rate_limiter_task: Arc<std::sync::Mutex<tokio::task::JoinHandle<()>>>,
+    max_retries: u32,
+}
+
+#[derive(Debug)]
+struct Session {
     client: reqwest::Client,
-    pub(crate) session_token: String,
+    session_token: String,
+}
+
+/// Spawns the background task that refills the rate limiter's token bucket,
+/// returning the bucket itself and a handle to the task so it can be
+/// stopped when the bucket is replaced.
+///
+/// `config.interval / config.max_requests` is clamped to at least 1ms so a
+/// degenerate config (e.g. `interval: Duration::ZERO`) can't produce a
+/// zero-length tick, which would panic in `tokio::time::interval`.
+fn spawn_token_bucket(config: &RateLimitConfig) -> (Arc<Semaphore>, tokio::task::JoinHandle<()>) {
+    let max_requests = config.max_requests.max(1);
+    let rate_limiter = Arc::new(Semaphore::new(max_requests as usize));
+    let refill_interval = (config.interval / max_requests).max(Duration::from_millis(1));
+
+    let refill = rate_limiter.clone();
+    let task = tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(refill_interval);
+        loop {
+            ticker.tick().await;
+            if refill.available_permits() < max_requests as usize {
+                refill.add_permits(1);
+            }
+        }
+    });
+
+    (rate_limiter, task)
 }
 
 impl TastyTrade {
@@ -33,20 +103,113 @@ impl TastyTrade {
             })
             .send()
             .await?;
-        let json = resp
-            .inspect_json::<TastyApiResponse<LoginResponse>, TastyError>(|text| println!("{text}"))
+        let response = Self::unwrap_response::<LoginResponse>(resp).await?;
+
+        let rate_limit = RateLimitConfig::default();
+        let (rate_limiter, rate_limiter_task) = spawn_token_bucket(&rate_limit);
+        Ok(Self {
+            login: login.to_owned(),
+            remember_token: Arc::new(RwLock::new(response.remember_token)),
+            session: Arc::new(RwLock::new(Session {
+                client: Self::build_client(&response.session_token)?,
+                session_token: response.session_token,
+            })),
+            rate_limiter,
+            rate_limiter_task: Arc::new(std::sync::Mutex::new(rate_limiter_task)),
+            max_retries: rate_limit.max_retries,
+        })
+    }
+
+    /// Re-establishes a session from a previously stored remember-token,
+    /// without needing the user's password again.
+    pub async fn from_remember_token(login: &str, remember_token: &str) -> Result<Self> {
+        let client = reqwest::Client::new();
+        let resp = client
+            .post(format!("{BASE_URL}/sessions"))
+            .json(&RememberMeCredentials {
+                login,
+                remember_token,
+            })
+            .send()
             .await?;
-        let response = match json {
-            TastyApiResponse::Success(s) => Ok(s),
-            TastyApiResponse::Error { error } => Err(error),
-        }?
-        .data;
+        let response = Self::unwrap_response::<LoginResponse>(resp).await?;
 
+        let rate_limit = RateLimitConfig::default();
+        let (rate_limiter, rate_limiter_task) = spawn_token_bucket(&rate_limit);
+        Ok(Self {
+            login: login.to_owned(),
+            remember_token: Arc::new(RwLock::new(
+                response.remember_token.or_else(|| Some(remember_token.to_owned())),
+            )),
+            session: Arc::new(RwLock::new(Session {
+                client: Self::build_client(&response.session_token)?,
+                session_token: response.session_token,
+            })),
+            rate_limiter,
+            rate_limiter_task: Arc::new(std::sync::Mutex::new(rate_limiter_task)),
+            max_retries: rate_limit.max_retries,
+        })
+    }
+
+    /// Overrides the client-side throttling and retry budget, replacing the
+    /// default [`RateLimitConfig`]. The previous bucket's background refill
+    /// task is aborted so it doesn't keep running after being replaced.
+    pub fn with_rate_limit(self, config: RateLimitConfig) -> Self {
+        let (rate_limiter, rate_limiter_task) = spawn_token_bucket(&config);
+
+        if let Ok(old_task) = self.rate_limiter_task.lock() {
+            old_task.abort();
+        }
+
+        Self {
+            max_retries: config.max_retries,
+            rate_limiter,
+            rate_limiter_task: Arc::new(std::sync::Mutex::new(rate_limiter_task)),
+            ..self
+        }
+    }
+
+    /// Mints a fresh `session_token` using the stored remember-token and
+    /// swaps it into the shared client, so other in-flight requests start
+    /// using it immediately.
+    async fn refresh_session(&self) -> Result<()> {
+        let Some(remember_token) = self.remember_token.read().await.clone() else {
+            return Ok(());
+        };
+
+        let client = reqwest::Client::new();
+        let resp = client
+            .post(format!("{BASE_URL}/sessions"))
+            .json(&RememberMeCredentials {
+                login: &self.login,
+                remember_token: &remember_token,
+            })
+            .send()
+            .await?;
+        let response = Self::unwrap_response::<LoginResponse>(resp).await?;
+
+        if let Some(new_remember_token) = response.remember_token {
+            *self.remember_token.write().await = Some(new_remember_token);
+        }
+        let mut session = self.session.write().await;
+        session.client = Self::build_client(&response.session_token)?;
+        session.session_token = response.session_token;
+
+        Ok(())
+    }
+
+    /// The current session token, e.g. for authenticating a websocket
+    /// connection that can't reuse the REST `reqwest::Client`.
+    pub(crate) async fn session_token(&self) -> String {
+        self.session.read().await.session_token.clone()
+    }
+
+    fn build_client(session_token: &str) -> Result<reqwest::Client> {
         let mut headers = HeaderMap::new();
 
         headers.insert(
             header::AUTHORIZATION,
-            HeaderValue::from_str(&response.session_token).unwrap(),
+            HeaderValue::from_str(session_token).unwrap(),
         );
         headers.insert(
             header::CONTENT_TYPE,
@@ -56,59 +219,188 @@ impl TastyTrade {
             header::USER_AGENT,
             HeaderValue::from_str("tastytrade-rs").unwrap(),
         );
-        let client = ClientBuilder::new()
+
+        Ok(ClientBuilder::new()
             .default_headers(headers)
             .build()
-            .expect("Could not create client");
-
-        Ok(Self {
-            client,
-            session_token: response.session_token,
-        })
+            .expect("Could not create client"))
     }
 
-    pub async fn get<T: DeserializeOwned, U: AsRef<str>>(&self, url: U) -> Result<T> {
-        let url = format!("{BASE_URL}{}", url.as_ref());
-
-        let result = self
-            .client
-            .get(url)
-            .send()
-            .await?
-            .inspect_json::<TastyApiResponse<T>, TastyError>(move |text| {
-                println!("{text}");
-            })
-            //.json::<TastyApiResponse<T>>()
+    async fn unwrap_response<T: DeserializeOwned>(resp: reqwest::Response) -> Result<T> {
+        let json = resp
+            .inspect_json::<TastyApiResponse<T>, TastyError>(|text| println!("{text}"))
             .await?;
 
-        match result {
+        match json {
             TastyApiResponse::Success(s) => Ok(s.data),
             TastyApiResponse::Error { error } => Err(error.into()),
         }
     }
 
+    async fn send_once(&self, method: Method, url: &str, body: Option<String>) -> Result<reqwest::Response> {
+        let client = self.session.read().await.client.clone();
+        let mut builder = client.request(method, url);
+        if let Some(body) = body {
+            builder = builder.body(body);
+        }
+        Ok(builder.send().await?)
+    }
+
+    async fn request<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<String>,
+    ) -> Result<T> {
+        let full_url = format!("{BASE_URL}{url}");
+
+        let mut attempt = 0;
+        loop {
+            // `forget()`, not `drop()`: a dropped `SemaphorePermit` returns
+            // itself to the semaphore immediately, which would let every
+            // request through regardless of `rate_limiter_task`'s refill
+            // schedule. Forgetting it means the permit is only replenished
+            // by that background task, which is the whole point of
+            // `RateLimitConfig`.
+            let permit = self.rate_limiter.acquire().await.unwrap();
+            permit.forget();
+            let resp = self
+                .send_once(method.clone(), &full_url, body.clone())
+                .await?;
+
+            let resp = if resp.status() == StatusCode::UNAUTHORIZED {
+                self.refresh_session().await?;
+                let permit = self.rate_limiter.acquire().await.unwrap();
+                permit.forget();
+                self.send_once(method.clone(), &full_url, body.clone())
+                    .await?
+            } else {
+                resp
+            };
+
+            if Self::is_retryable(resp.status()) && attempt < self.max_retries {
+                attempt += 1;
+                tokio::time::sleep(Self::retry_delay(&resp, attempt)).await;
+                continue;
+            }
+
+            return Self::unwrap_response(resp).await;
+        }
+    }
+
+    fn is_retryable(status: StatusCode) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+    }
+
+    fn retry_delay(resp: &reqwest::Response, attempt: u32) -> Duration {
+        let retry_after = resp
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(Duration::from_secs);
+
+        retry_after.unwrap_or_else(|| Duration::from_millis(200 * 2u64.pow(attempt)))
+    }
+
+    pub async fn get<T: DeserializeOwned, U: AsRef<str>>(&self, url: U) -> Result<T> {
+        self.request(Method::GET, url.as_ref(), None).await
+    }
+
     pub async fn post<R, P, U>(&self, url: U, payload: P) -> Result<R>
     where
         R: DeserializeOwned,
         P: Serialize,
         U: AsRef<str>,
     {
-        let url = format!("{BASE_URL}{}", url.as_ref());
-        let result = self
-            .client
-            .post(url)
-            .body(serde_json::to_string(&payload).unwrap())
-            .send()
-            .await?
-            .inspect_json::<TastyApiResponse<R>, TastyError>(move |text| {
-                println!("{text}");
-            })
-            //.json::<TastyApiResponse<R>>()
-            .await?;
+        let body = serde_json::to_string(&payload).unwrap();
+        self.request(Method::POST, url.as_ref(), Some(body)).await
+    }
 
-        match result {
-            TastyApiResponse::Success(s) => Ok(s.data),
-            TastyApiResponse::Error { error } => Err(error.into()),
+    pub async fn put<R, P, U>(&self, url: U, payload: P) -> Result<R>
+    where
+        R: DeserializeOwned,
+        P: Serialize,
+        U: AsRef<str>,
+    {
+        let body = serde_json::to_string(&payload).unwrap();
+        self.request(Method::PUT, url.as_ref(), Some(body)).await
+    }
+
+    pub async fn delete<T: DeserializeOwned, U: AsRef<str>>(&self, url: U) -> Result<T> {
+        self.request(Method::DELETE, url.as_ref(), None).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response_with_retry_after(status: StatusCode, retry_after: Option<&str>) -> reqwest::Response {
+        let mut builder = http::Response::builder().status(status);
+        if let Some(retry_after) = retry_after {
+            builder = builder.header(reqwest::header::RETRY_AFTER, retry_after);
         }
+        reqwest::Response::from(builder.body(reqwest::Body::default()).unwrap())
+    }
+
+    #[test]
+    fn is_retryable_accepts_rate_limited_and_server_errors() {
+        assert!(TastyTrade::is_retryable(StatusCode::TOO_MANY_REQUESTS));
+        assert!(TastyTrade::is_retryable(StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(TastyTrade::is_retryable(StatusCode::SERVICE_UNAVAILABLE));
+    }
+
+    #[test]
+    fn is_retryable_rejects_client_errors_and_success() {
+        assert!(!TastyTrade::is_retryable(StatusCode::OK));
+        assert!(!TastyTrade::is_retryable(StatusCode::UNAUTHORIZED));
+        assert!(!TastyTrade::is_retryable(StatusCode::BAD_REQUEST));
+    }
+
+    #[test]
+    fn retry_delay_honors_retry_after_header() {
+        let resp = response_with_retry_after(StatusCode::TOO_MANY_REQUESTS, Some("5"));
+        assert_eq!(TastyTrade::retry_delay(&resp, 1), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn retry_delay_backs_off_exponentially_without_retry_after_header() {
+        let resp = response_with_retry_after(StatusCode::INTERNAL_SERVER_ERROR, None);
+        assert_eq!(TastyTrade::retry_delay(&resp, 1), Duration::from_millis(400));
+        assert_eq!(TastyTrade::retry_delay(&resp, 3), Duration::from_millis(1600));
+    }
+
+    fn test_client(remember_token: Option<&str>) -> TastyTrade {
+        let (rate_limiter, rate_limiter_task) = spawn_token_bucket(&RateLimitConfig::default());
+        TastyTrade {
+            login: "login".to_owned(),
+            remember_token: Arc::new(RwLock::new(remember_token.map(str::to_owned))),
+            session: Arc::new(RwLock::new(Session {
+                client: reqwest::Client::new(),
+                session_token: "stale-token".to_owned(),
+            })),
+            rate_limiter,
+            rate_limiter_task: Arc::new(std::sync::Mutex::new(rate_limiter_task)),
+            max_retries: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn refresh_session_is_a_no_op_without_a_stored_remember_token() {
+        let tasty = test_client(None);
+        assert!(tasty.refresh_session().await.is_ok());
+        assert_eq!(tasty.session_token().await, "stale-token");
+    }
+
+    #[tokio::test]
+    async fn spawn_token_bucket_clamps_a_zero_interval_instead_of_panicking() {
+        let (rate_limiter, task) = spawn_token_bucket(&RateLimitConfig {
+            max_requests: 10,
+            interval: Duration::ZERO,
+            max_retries: 0,
+        });
+        assert_eq!(rate_limiter.available_permits(), 10);
+        task.abort();
     }
 }